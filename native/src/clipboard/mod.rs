@@ -0,0 +1,5 @@
+//! Clipboard capture, writing, and monitoring
+
+pub mod monitor;
+pub mod osc52;
+pub mod types;
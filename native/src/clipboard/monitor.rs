@@ -1,9 +1,16 @@
 //! Clipboard monitoring using NSPasteboard
 
-use crate::clipboard::types::{ClipboardContentType, ClipboardMonitorConfig, NativeClipboardItem};
+use crate::clipboard::types::{
+    ClipboardContentType, ClipboardMonitorConfig, ClipboardReadConfig, NativeClipboardItem,
+    NativeClipboardWrite,
+};
 use chrono::Utc;
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi::{JsFunction, Result};
+use napi_derive::napi;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
 use std::thread;
 use std::time::Duration;
 use uuid::Uuid;
@@ -11,7 +18,7 @@ use uuid::Uuid;
 #[cfg(target_os = "macos")]
 use cocoa::base::{id, nil};
 #[cfg(target_os = "macos")]
-use cocoa::foundation::{NSArray, NSString};
+use cocoa::foundation::{NSArray, NSData, NSString};
 #[cfg(target_os = "macos")]
 use objc::{class, msg_send, sel, sel_impl};
 
@@ -19,11 +26,105 @@ use objc::{class, msg_send, sel, sel_impl};
 struct MonitorState {
     running: AtomicBool,
     last_change_count: AtomicI64,
+    /// Lets [`stop_clipboard_monitor`] wake the polling thread immediately
+    /// instead of waiting out the rest of its `poll_interval_ms` sleep.
+    wake: Condvar,
+    wake_lock: Mutex<()>,
+}
+
+/// A running monitor's background thread and the state used to stop it
+struct MonitorHandle {
+    state: Arc<MonitorState>,
+    thread: thread::JoinHandle<()>,
+}
+
+/// The single active monitor, if one has been started
+fn active_monitor() -> &'static Mutex<Option<MonitorHandle>> {
+    static ACTIVE_MONITOR: OnceLock<Mutex<Option<MonitorHandle>>> = OnceLock::new();
+    ACTIVE_MONITOR.get_or_init(|| Mutex::new(None))
+}
+
+/// Start a background thread that polls the pasteboard's change count and
+/// delivers new items to `callback`, skipping copies made by apps listed in
+/// `config.excluded_bundle_ids`.
+///
+/// Calling this while a monitor is already running stops the previous one first.
+#[napi]
+pub fn start_clipboard_monitor(config: ClipboardMonitorConfig, callback: JsFunction) -> Result<()> {
+    stop_clipboard_monitor();
+
+    let tsfn: ThreadsafeFunction<NativeClipboardItem, ErrorStrategy::Fatal> =
+        callback.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+
+    let poll_interval_ms = config.poll_interval_ms.unwrap_or(500).max(50) as u64;
+    let excluded_bundle_ids = config.excluded_bundle_ids.unwrap_or_default();
+
+    let state = Arc::new(MonitorState {
+        running: AtomicBool::new(true),
+        last_change_count: AtomicI64::new(get_clipboard_change_count()),
+        wake: Condvar::new(),
+        wake_lock: Mutex::new(()),
+    });
+
+    let thread_state = Arc::clone(&state);
+    let thread = thread::spawn(move || {
+        while thread_state.running.load(Ordering::SeqCst) {
+            let guard = thread_state.wake_lock.lock().unwrap();
+            if !thread_state.running.load(Ordering::SeqCst) {
+                break;
+            }
+            let _ = thread_state
+                .wake
+                .wait_timeout(guard, Duration::from_millis(poll_interval_ms))
+                .unwrap();
+
+            if !thread_state.running.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let current = get_clipboard_change_count();
+            let previous = thread_state.last_change_count.swap(current, Ordering::SeqCst);
+            if current == previous {
+                continue;
+            }
+
+            if let Some(bundle_id) = get_frontmost_app_bundle_id() {
+                if excluded_bundle_ids.contains(&bundle_id) {
+                    continue;
+                }
+            }
+
+            if let Some(item) = read_clipboard_content(&ClipboardReadConfig::default()) {
+                tsfn.call(item, ThreadsafeFunctionCallMode::NonBlocking);
+            }
+        }
+    });
+
+    *active_monitor().lock().unwrap() = Some(MonitorHandle { state, thread });
+    Ok(())
+}
+
+/// Stop the background monitor started by [`start_clipboard_monitor`], if any.
+///
+/// Wakes the polling thread via its condvar rather than waiting for its next
+/// `poll_interval_ms` tick, so this returns promptly instead of blocking the
+/// calling (JS main) thread for up to the full poll interval.
+#[napi]
+pub fn stop_clipboard_monitor() {
+    let handle = active_monitor().lock().unwrap().take();
+    if let Some(handle) = handle {
+        {
+            let _guard = handle.state.wake_lock.lock().unwrap();
+            handle.state.running.store(false, Ordering::SeqCst);
+            handle.state.wake.notify_all();
+        }
+        let _ = handle.thread.join();
+    }
 }
 
 /// Read the current clipboard content
 #[cfg(target_os = "macos")]
-pub fn read_clipboard_content() -> Option<NativeClipboardItem> {
+pub fn read_clipboard_content(config: &ClipboardReadConfig) -> Option<NativeClipboardItem> {
     unsafe {
         let pasteboard: id = msg_send![class!(NSPasteboard), generalPasteboard];
         if pasteboard == nil {
@@ -38,106 +139,260 @@ pub fn read_clipboard_content() -> Option<NativeClipboardItem> {
             return None;
         }
 
-        let mut item = NativeClipboardItem {
-            id: Uuid::new_v4().to_string(),
-            content_type: ClipboardContentType::Unknown.to_string(),
-            created_at: Utc::now().to_rfc3339(),
-            source_app_bundle_id: None,
-            source_app_name: None,
-            plain_text: None,
-            rtf_data: None,
-            html_data: None,
-            image_data: None,
-            image_width: None,
-            image_height: None,
-            image_format: None,
-            file_paths: None,
-            detected_urls: None,
-        };
+        let source_app_bundle_id = get_frontmost_app_bundle_id();
+        let source_app_name = get_frontmost_app_name();
 
-        // Try to get source app from pasteboard
-        item.source_app_bundle_id = get_frontmost_app_bundle_id();
-        item.source_app_name = get_frontmost_app_name();
+        clipboard_item_from_types(
+            pasteboard,
+            types,
+            config,
+            source_app_bundle_id,
+            source_app_name,
+            read_file_urls,
+        )
+    }
+}
 
-        // Check for file URLs first
-        let file_url_type = NSString::alloc(nil).init_str("public.file-url");
-        let has_files: bool = msg_send![types, containsObject: file_url_type];
+/// Read every item on the general pasteboard individually, as Chromium's mac
+/// clipboard provider does, instead of merging them into a single result.
+/// This preserves multi-selection copies (several files, a link plus a
+/// separate title item) that [`read_clipboard_content`] would collapse.
+#[cfg(target_os = "macos")]
+pub fn read_clipboard_items(config: &ClipboardReadConfig) -> Vec<NativeClipboardItem> {
+    unsafe {
+        let pasteboard: id = msg_send![class!(NSPasteboard), generalPasteboard];
+        if pasteboard == nil {
+            return Vec::new();
+        }
 
-        if has_files {
-            if let Some(paths) = read_file_urls(pasteboard) {
-                item.file_paths = Some(paths);
-                item.content_type = ClipboardContentType::File.to_string();
-                return Some(item);
-            }
+        let pb_items: id = msg_send![pasteboard, pasteboardItems];
+        if pb_items == nil {
+            return Vec::new();
         }
 
-        // Check for images
-        let png_type = NSString::alloc(nil).init_str("public.png");
-        let tiff_type = NSString::alloc(nil).init_str("public.tiff");
-        let has_png: bool = msg_send![types, containsObject: png_type];
-        let has_tiff: bool = msg_send![types, containsObject: tiff_type];
-
-        if has_png || has_tiff {
-            if let Some((data, format)) = read_image_data(pasteboard) {
-                item.image_data = Some(data);
-                item.image_format = Some(format);
-                item.content_type = ClipboardContentType::Image.to_string();
-                // Image dimensions would require additional processing
-                return Some(item);
+        let source_app_bundle_id = get_frontmost_app_bundle_id();
+        let source_app_name = get_frontmost_app_name();
+
+        let count: usize = msg_send![pb_items, count];
+        let mut result = Vec::with_capacity(count);
+        for i in 0..count {
+            let pb_item: id = msg_send![pb_items, objectAtIndex: i];
+            if let Some(item) = item_from_pasteboard_item(
+                pb_item,
+                config,
+                source_app_bundle_id.clone(),
+                source_app_name.clone(),
+            ) {
+                result.push(item);
             }
         }
 
-        // Check for HTML
-        let html_type = NSString::alloc(nil).init_str("public.html");
-        let has_html: bool = msg_send![types, containsObject: html_type];
+        result
+    }
+}
 
-        if has_html {
-            if let Some(html) = read_string_for_type(pasteboard, "public.html") {
-                item.html_data = Some(html);
-            }
+/// Build a [`NativeClipboardItem`] from a single `NSPasteboardItem`, scoped to
+/// that item's own types rather than the pasteboard's merged type list.
+#[cfg(target_os = "macos")]
+fn item_from_pasteboard_item(
+    pb_item: id,
+    config: &ClipboardReadConfig,
+    source_app_bundle_id: Option<String>,
+    source_app_name: Option<String>,
+) -> Option<NativeClipboardItem> {
+    unsafe {
+        if pb_item == nil {
+            return None;
         }
 
-        // Check for RTF
-        let rtf_type = NSString::alloc(nil).init_str("public.rtf");
-        let has_rtf: bool = msg_send![types, containsObject: rtf_type];
+        let types: id = msg_send![pb_item, types];
+        if types == nil {
+            return None;
+        }
 
-        if has_rtf {
-            if let Some(rtf) = read_string_for_type(pasteboard, "public.rtf") {
-                item.rtf_data = Some(rtf);
-                item.content_type = ClipboardContentType::RichText.to_string();
-            }
+        clipboard_item_from_types(
+            pb_item,
+            types,
+            config,
+            source_app_bundle_id,
+            source_app_name,
+            |item| read_file_url_from_item(item).map(|path| vec![path]),
+        )
+    }
+}
+
+/// Shared per-type detection cascade (files, images, color, HTML, RTF, text)
+/// used by both [`read_clipboard_content`] (scanning the pasteboard's merged
+/// types) and [`item_from_pasteboard_item`] (scanning one item's types).
+/// `read_file_urls` is the one thing that differs between the two: the
+/// pasteboard can resolve several `NSURL`s via `readObjectsForClasses:`,
+/// while a single `NSPasteboardItem` only exposes its own `public.file-url`
+/// string.
+#[cfg(target_os = "macos")]
+unsafe fn clipboard_item_from_types(
+    source: id,
+    types: id,
+    config: &ClipboardReadConfig,
+    source_app_bundle_id: Option<String>,
+    source_app_name: Option<String>,
+    read_file_urls: impl FnOnce(id) -> Option<Vec<String>>,
+) -> Option<NativeClipboardItem> {
+    let mut item = NativeClipboardItem {
+        id: Uuid::new_v4().to_string(),
+        content_type: ClipboardContentType::Unknown.to_string(),
+        created_at: Utc::now().to_rfc3339(),
+        source_app_bundle_id,
+        source_app_name,
+        plain_text: None,
+        rtf_data: None,
+        html_data: None,
+        image_data: None,
+        image_width: None,
+        image_height: None,
+        image_format: None,
+        file_paths: None,
+        detected_urls: None,
+        url_title: None,
+        custom_data: None,
+        smart_paste: None,
+        color_rgba: None,
+    };
+
+    // Detect WebKit's smart-paste marker
+    let smart_paste_type = NSString::alloc(nil).init_str("NeXT smart paste pasteboard type");
+    item.smart_paste = Some(msg_send![types, containsObject: smart_paste_type]);
+
+    // Capture any extra UTIs the caller asked for that we don't natively parse
+    if let Some(extra_types) = config.extra_uti_types.as_ref() {
+        item.custom_data = read_custom_data(source, types, extra_types);
+    }
+
+    // Check for file URLs first
+    let file_url_type = NSString::alloc(nil).init_str("public.file-url");
+    let has_files: bool = msg_send![types, containsObject: file_url_type];
+
+    if has_files {
+        if let Some(paths) = read_file_urls(source) {
+            item.file_paths = Some(paths);
+            item.content_type = ClipboardContentType::File.to_string();
+            return Some(item);
         }
+    }
 
-        // Check for plain text
-        let text_type = NSString::alloc(nil).init_str("public.utf8-plain-text");
-        let has_text: bool = msg_send![types, containsObject: text_type];
+    // Check for images
+    let png_type = NSString::alloc(nil).init_str("public.png");
+    let tiff_type = NSString::alloc(nil).init_str("public.tiff");
+    let has_png: bool = msg_send![types, containsObject: png_type];
+    let has_tiff: bool = msg_send![types, containsObject: tiff_type];
 
-        if has_text {
-            if let Some(text) = read_string_for_type(pasteboard, "public.utf8-plain-text") {
-                // Detect URLs in text
-                let urls = detect_urls(&text);
-                if !urls.is_empty() {
-                    item.detected_urls = Some(urls.clone());
-                    // If the entire text is a single URL, mark as Link type
-                    if urls.len() == 1 && text.trim() == urls[0] {
-                        item.content_type = ClipboardContentType::Link.to_string();
-                    }
-                }
+    if has_png || has_tiff {
+        if let Some((data, format, width, height)) = read_image_data(source, config) {
+            item.image_data = Some(data);
+            item.image_format = Some(format);
+            item.image_width = width;
+            item.image_height = height;
+            item.content_type = ClipboardContentType::Image.to_string();
+            return Some(item);
+        }
+    }
+
+    // Check for a native NSColor archived on the pasteboard
+    let color_type = NSString::alloc(nil).init_str("com.apple.cocoa.pasteboard.color");
+    let has_color: bool = msg_send![types, containsObject: color_type];
+
+    if has_color {
+        if let Some(rgba) = read_native_color(source) {
+            item.color_rgba = Some(rgba);
+            item.content_type = ClipboardContentType::Color.to_string();
+            return Some(item);
+        }
+    }
+
+    // Check for HTML
+    let html_type = NSString::alloc(nil).init_str("public.html");
+    let has_html: bool = msg_send![types, containsObject: html_type];
+
+    if has_html {
+        item.html_data = read_string_for_type(source, "public.html");
+    }
+
+    // Check for RTF
+    let rtf_type = NSString::alloc(nil).init_str("public.rtf");
+    let has_rtf: bool = msg_send![types, containsObject: rtf_type];
+
+    if has_rtf {
+        if let Some(rtf) = read_string_for_type(source, "public.rtf") {
+            item.rtf_data = Some(rtf);
+            item.content_type = ClipboardContentType::RichText.to_string();
+        }
+    }
+
+    // Check for plain text
+    let text_type = NSString::alloc(nil).init_str("public.utf8-plain-text");
+    let has_text: bool = msg_send![types, containsObject: text_type];
 
-                item.plain_text = Some(text);
-                if item.content_type == ClipboardContentType::Unknown.to_string() {
-                    item.content_type = ClipboardContentType::Text.to_string();
+    if has_text {
+        if let Some(text) = read_string_for_type(source, "public.utf8-plain-text") {
+            // Detect URLs in text
+            let urls = detect_urls(&text);
+            if !urls.is_empty() {
+                item.detected_urls = Some(urls.clone());
+                // If the entire text is a single URL, mark as Link type
+                if urls.len() == 1 && text.trim() == urls[0] {
+                    item.content_type = ClipboardContentType::Link.to_string();
+                    item.url_title = read_string_for_type(source, "public.url-name");
                 }
-                return Some(item);
             }
-        }
 
-        // If we have RTF but no plain text, still return
-        if item.rtf_data.is_some() {
+            // A CSS/hex color, pasted as plain text
+            if let Some(rgba) = parse_css_color(&text) {
+                item.color_rgba = Some(rgba);
+                item.content_type = ClipboardContentType::Color.to_string();
+            }
+
+            item.plain_text = Some(text);
+            if item.content_type == ClipboardContentType::Unknown.to_string() {
+                item.content_type = ClipboardContentType::Text.to_string();
+            }
             return Some(item);
         }
+    }
 
-        None
+    // If we have RTF but no plain text, still return
+    if item.rtf_data.is_some() {
+        return Some(item);
+    }
+
+    None
+}
+
+/// Read an `NSPasteboardItem`'s `public.file-url` string and resolve it to a
+/// filesystem path, since items (unlike the pasteboard itself) don't support
+/// `readObjectsForClasses:options:`.
+#[cfg(target_os = "macos")]
+fn read_file_url_from_item(pb_item: id) -> Option<String> {
+    unsafe {
+        let url_string = read_string_for_type(pb_item, "public.file-url")?;
+        let ns_string = NSString::alloc(nil).init_str(&url_string);
+        let url: id = msg_send![class!(NSURL), URLWithString: ns_string];
+        if url == nil {
+            return None;
+        }
+
+        let path: id = msg_send![url, path];
+        if path == nil {
+            return None;
+        }
+
+        let bytes: *const i8 = msg_send![path, UTF8String];
+        if bytes.is_null() {
+            return None;
+        }
+
+        std::ffi::CStr::from_ptr(bytes)
+            .to_str()
+            .ok()
+            .map(|s| s.to_string())
     }
 }
 
@@ -161,6 +416,72 @@ fn read_string_for_type(pasteboard: id, type_str: &str) -> Option<String> {
     }
 }
 
+/// UTIs already captured into dedicated `NativeClipboardItem` fields, so
+/// `extra_uti_types` requests for them are ignored rather than duplicated.
+#[cfg(target_os = "macos")]
+const BUILTIN_UTIS: &[&str] = &[
+    "public.utf8-plain-text",
+    "public.html",
+    "public.rtf",
+    "public.png",
+    "public.tiff",
+    "public.file-url",
+    "com.apple.cocoa.pasteboard.color",
+    "public.url-name",
+];
+
+#[cfg(target_os = "macos")]
+fn read_custom_data(
+    pasteboard: id,
+    types: id,
+    extra_types: &[String],
+) -> Option<HashMap<String, Vec<u8>>> {
+    unsafe {
+        let mut custom_data = HashMap::new();
+
+        for uti in extra_types {
+            if BUILTIN_UTIS.contains(&uti.as_str()) {
+                continue;
+            }
+
+            let ns_type = NSString::alloc(nil).init_str(uti);
+            let has_type: bool = msg_send![types, containsObject: ns_type];
+            if !has_type {
+                continue;
+            }
+
+            if let Some(bytes) = read_data_for_type(pasteboard, uti) {
+                custom_data.insert(uti.clone(), bytes);
+            }
+        }
+
+        if custom_data.is_empty() {
+            None
+        } else {
+            Some(custom_data)
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn read_data_for_type(pasteboard: id, type_str: &str) -> Option<Vec<u8>> {
+    unsafe {
+        let ns_type = NSString::alloc(nil).init_str(type_str);
+        let data: id = msg_send![pasteboard, dataForType: ns_type];
+        if data == nil {
+            return None;
+        }
+
+        let length: usize = msg_send![data, length];
+        let bytes: *const u8 = msg_send![data, bytes];
+        if bytes.is_null() || length == 0 {
+            return None;
+        }
+
+        Some(std::slice::from_raw_parts(bytes, length).to_vec())
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn read_file_urls(pasteboard: id) -> Option<Vec<String>> {
     unsafe {
@@ -201,8 +522,15 @@ fn read_file_urls(pasteboard: id) -> Option<Vec<String>> {
     }
 }
 
+/// Read image bytes from the pasteboard along with their pixel dimensions.
+///
+/// When `config.normalize_tiff_to_png` is set, TIFF data is transcoded to PNG
+/// via `NSBitmapImageRep` so `image_format` is predictable for consumers.
 #[cfg(target_os = "macos")]
-fn read_image_data(pasteboard: id) -> Option<(Vec<u8>, String)> {
+fn read_image_data(
+    pasteboard: id,
+    config: &ClipboardReadConfig,
+) -> Option<(Vec<u8>, String, Option<u32>, Option<u32>)> {
     unsafe {
         // Try PNG first
         let png_type = NSString::alloc(nil).init_str("public.png");
@@ -213,7 +541,9 @@ fn read_image_data(pasteboard: id) -> Option<(Vec<u8>, String)> {
             let bytes: *const u8 = msg_send![png_data, bytes];
             if !bytes.is_null() && length > 0 {
                 let slice = std::slice::from_raw_parts(bytes, length);
-                return Some((slice.to_vec(), "png".to_string()));
+                let data = slice.to_vec();
+                let (width, height) = image_dimensions(png_data, &data, "png");
+                return Some((data, "png".to_string(), width, height));
             }
         }
 
@@ -226,7 +556,16 @@ fn read_image_data(pasteboard: id) -> Option<(Vec<u8>, String)> {
             let bytes: *const u8 = msg_send![tiff_data, bytes];
             if !bytes.is_null() && length > 0 {
                 let slice = std::slice::from_raw_parts(bytes, length);
-                return Some((slice.to_vec(), "tiff".to_string()));
+                let data = slice.to_vec();
+                let (width, height) = image_dimensions(tiff_data, &data, "tiff");
+
+                if config.normalize_tiff_to_png.unwrap_or(false) {
+                    if let Some(png) = tiff_data_to_png(tiff_data) {
+                        return Some((png, "png".to_string(), width, height));
+                    }
+                }
+
+                return Some((data, "tiff".to_string(), width, height));
             }
         }
 
@@ -234,6 +573,84 @@ fn read_image_data(pasteboard: id) -> Option<(Vec<u8>, String)> {
     }
 }
 
+/// Read pixel dimensions via `NSBitmapImageRep`, falling back to parsing the
+/// raw header bytes when AppKit fails to produce a usable size.
+#[cfg(target_os = "macos")]
+unsafe fn image_dimensions(ns_data: id, raw: &[u8], format: &str) -> (Option<u32>, Option<u32>) {
+    let bitmap: id = msg_send![class!(NSBitmapImageRep), imageRepWithData: ns_data];
+    if bitmap != nil {
+        let width: isize = msg_send![bitmap, pixelsWide];
+        let height: isize = msg_send![bitmap, pixelsHigh];
+        if width > 0 && height > 0 {
+            return (Some(width as u32), Some(height as u32));
+        }
+    }
+
+    parse_image_dimensions(raw, format)
+}
+
+/// Unarchive a native `NSColor` written to the pasteboard and normalize it
+/// to RGBA, converting through the generic RGB color space first since the
+/// archived color may be in a different space (e.g. grayscale).
+#[cfg(target_os = "macos")]
+fn read_native_color(pasteboard: id) -> Option<[u8; 4]> {
+    unsafe {
+        let color_type = NSString::alloc(nil).init_str("com.apple.cocoa.pasteboard.color");
+        let data: id = msg_send![pasteboard, dataForType: color_type];
+        if data == nil {
+            return None;
+        }
+
+        let color: id = msg_send![class!(NSKeyedUnarchiver), unarchiveObjectWithData: data];
+        if color == nil {
+            return None;
+        }
+
+        let rgb_space: id = msg_send![class!(NSColorSpace), genericRGBColorSpace];
+        let converted: id = msg_send![color, colorUsingColorSpace: rgb_space];
+        if converted == nil {
+            return None;
+        }
+
+        let red: f64 = msg_send![converted, redComponent];
+        let green: f64 = msg_send![converted, greenComponent];
+        let blue: f64 = msg_send![converted, blueComponent];
+        let alpha: f64 = msg_send![converted, alphaComponent];
+
+        Some([
+            (red * 255.0).round() as u8,
+            (green * 255.0).round() as u8,
+            (blue * 255.0).round() as u8,
+            (alpha * 255.0).round() as u8,
+        ])
+    }
+}
+
+/// NSBitmapImageFileType.png, from AppKit's NSBitmapImageRep.h
+#[cfg(target_os = "macos")]
+const NS_BITMAP_IMAGE_FILE_TYPE_PNG: i64 = 4;
+
+#[cfg(target_os = "macos")]
+unsafe fn tiff_data_to_png(tiff_data: id) -> Option<Vec<u8>> {
+    let bitmap: id = msg_send![class!(NSBitmapImageRep), imageRepWithData: tiff_data];
+    if bitmap == nil {
+        return None;
+    }
+
+    let png_data: id = msg_send![bitmap, representationUsingType: NS_BITMAP_IMAGE_FILE_TYPE_PNG properties: nil];
+    if png_data == nil {
+        return None;
+    }
+
+    let length: usize = msg_send![png_data, length];
+    let bytes: *const u8 = msg_send![png_data, bytes];
+    if bytes.is_null() || length == 0 {
+        return None;
+    }
+
+    Some(std::slice::from_raw_parts(bytes, length).to_vec())
+}
+
 #[cfg(target_os = "macos")]
 fn get_frontmost_app_bundle_id() -> Option<String> {
     unsafe {
@@ -300,6 +717,236 @@ pub fn get_clipboard_change_count() -> i64 {
     }
 }
 
+/// Write content to the general pasteboard, replacing its current contents.
+///
+/// Returns the resulting `changeCount` so callers can distinguish their own
+/// writes from external ones while a monitor is running.
+#[cfg(target_os = "macos")]
+pub fn clipboard_write(item: NativeClipboardWrite) -> i64 {
+    unsafe {
+        let pasteboard: id = msg_send![class!(NSPasteboard), generalPasteboard];
+        if pasteboard == nil {
+            return 0;
+        }
+
+        let _: i64 = msg_send![pasteboard, clearContents];
+
+        let mut types: Vec<id> = Vec::new();
+        if item.plain_text.is_some() {
+            types.push(NSString::alloc(nil).init_str("public.utf8-plain-text"));
+        }
+        if item.html_data.is_some() {
+            types.push(NSString::alloc(nil).init_str("public.html"));
+        }
+        if item.rtf_data.is_some() {
+            types.push(NSString::alloc(nil).init_str("public.rtf"));
+        }
+        if item.image_data.is_some() {
+            types.push(NSString::alloc(nil).init_str(image_uti(item.image_format.as_deref())));
+        }
+
+        if !types.is_empty() {
+            let ns_types = NSArray::arrayWithObjects(nil, &types);
+            let _: bool = msg_send![pasteboard, declareTypes: ns_types owner: nil];
+        }
+
+        if let Some(text) = item.plain_text.as_deref() {
+            let ns_type = NSString::alloc(nil).init_str("public.utf8-plain-text");
+            let ns_text = NSString::alloc(nil).init_str(text);
+            let _: bool = msg_send![pasteboard, setString: ns_text forType: ns_type];
+        }
+
+        if let Some(html) = item.html_data.as_deref() {
+            let ns_type = NSString::alloc(nil).init_str("public.html");
+            let ns_html = NSString::alloc(nil).init_str(html);
+            let _: bool = msg_send![pasteboard, setString: ns_html forType: ns_type];
+        }
+
+        if let Some(rtf) = item.rtf_data.as_deref() {
+            let ns_type = NSString::alloc(nil).init_str("public.rtf");
+            let ns_rtf = NSString::alloc(nil).init_str(rtf);
+            let _: bool = msg_send![pasteboard, setString: ns_rtf forType: ns_type];
+        }
+
+        if let Some(data) = item.image_data.as_ref() {
+            let ns_type = NSString::alloc(nil).init_str(image_uti(item.image_format.as_deref()));
+            let ns_data = NSData::dataWithBytes_length_(
+                nil,
+                data.as_ptr() as *const std::ffi::c_void,
+                data.len() as u64,
+            );
+            let _: bool = msg_send![pasteboard, setData: ns_data forType: ns_type];
+        }
+
+        if let Some(paths) = item.file_paths.as_ref().filter(|paths| !paths.is_empty()) {
+            let urls: Vec<id> = paths
+                .iter()
+                .map(|path| {
+                    let ns_path = NSString::alloc(nil).init_str(path);
+                    let url: id = msg_send![class!(NSURL), fileURLWithPath: ns_path];
+                    url
+                })
+                .collect();
+            let ns_urls = NSArray::arrayWithObjects(nil, &urls);
+            let _: bool = msg_send![pasteboard, writeObjects: ns_urls];
+        }
+
+        msg_send![pasteboard, changeCount]
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn image_uti(format: Option<&str>) -> &'static str {
+    match format {
+        Some("tiff") => "public.tiff",
+        _ => "public.png",
+    }
+}
+
+/// Parse pixel dimensions directly from PNG or TIFF header bytes, used when
+/// AppKit's image decoding APIs are unavailable or fail to produce a size.
+fn parse_image_dimensions(data: &[u8], format: &str) -> (Option<u32>, Option<u32>) {
+    match format {
+        "png" => parse_png_dimensions(data),
+        "tiff" => parse_tiff_dimensions(data),
+        _ => (None, None),
+    }
+}
+
+/// A PNG's IHDR chunk data starts right after the 8-byte signature and 8-byte
+/// chunk header, with big-endian width then height.
+fn parse_png_dimensions(data: &[u8]) -> (Option<u32>, Option<u32>) {
+    if data.len() < 24 {
+        return (None, None);
+    }
+
+    let width = u32::from_be_bytes(data[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(data[20..24].try_into().unwrap());
+    (Some(width), Some(height))
+}
+
+fn parse_tiff_dimensions(data: &[u8]) -> (Option<u32>, Option<u32>) {
+    fn read_ifd(data: &[u8]) -> Option<(u32, u32)> {
+        let little_endian = match data.get(0..2)? {
+            b"II" => true,
+            b"MM" => false,
+            _ => return None,
+        };
+
+        let read_u16 = |offset: usize| -> Option<u16> {
+            let bytes: [u8; 2] = data.get(offset..offset + 2)?.try_into().ok()?;
+            Some(if little_endian {
+                u16::from_le_bytes(bytes)
+            } else {
+                u16::from_be_bytes(bytes)
+            })
+        };
+        let read_u32 = |offset: usize| -> Option<u32> {
+            let bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+            Some(if little_endian {
+                u32::from_le_bytes(bytes)
+            } else {
+                u32::from_be_bytes(bytes)
+            })
+        };
+
+        let ifd_offset = read_u32(4)? as usize;
+        let entry_count = read_u16(ifd_offset)? as usize;
+
+        let mut width = None;
+        let mut height = None;
+        for i in 0..entry_count {
+            let entry_offset = ifd_offset + 2 + i * 12;
+            let tag = read_u16(entry_offset)?;
+            let value_type = read_u16(entry_offset + 2)?;
+            let value_offset = entry_offset + 8;
+            // ImageWidth/ImageLength are always SHORT (3) or LONG (4), both
+            // of which fit inline in the 4-byte value slot.
+            let value = if value_type == 3 {
+                read_u16(value_offset)? as u32
+            } else {
+                read_u32(value_offset)?
+            };
+
+            match tag {
+                256 => width = Some(value),
+                257 => height = Some(value),
+                _ => {}
+            }
+
+            if width.is_some() && height.is_some() {
+                break;
+            }
+        }
+
+        Some((width?, height?))
+    }
+
+    match read_ifd(data) {
+        Some((width, height)) => (Some(width), Some(height)),
+        None => (None, None),
+    }
+}
+
+/// Parse a CSS/hex color string (`#RGB`, `#RRGGBB`, `#RRGGBBAA`,
+/// `rgb()`/`rgba()`) into RGBA, or `None` if `text` isn't one.
+fn parse_css_color(text: &str) -> Option<[u8; 4]> {
+    let trimmed = text.trim();
+
+    match trimmed.strip_prefix('#') {
+        Some(hex) => parse_hex_color(hex),
+        None => parse_rgb_function(trimmed),
+    }
+}
+
+fn parse_hex_color(hex: &str) -> Option<[u8; 4]> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    match hex.len() {
+        3 => {
+            let mut chars = hex.chars();
+            let expand = |c: char| -> u8 {
+                let v = c.to_digit(16).unwrap() as u8;
+                v * 16 + v
+            };
+            Some([
+                expand(chars.next()?),
+                expand(chars.next()?),
+                expand(chars.next()?),
+                255,
+            ])
+        }
+        6 | 8 => {
+            let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+            let alpha = if hex.len() == 8 { byte(6)? } else { 255 };
+            Some([byte(0)?, byte(2)?, byte(4)?, alpha])
+        }
+        _ => None,
+    }
+}
+
+fn parse_rgb_function(text: &str) -> Option<[u8; 4]> {
+    let re = regex::Regex::new(
+        r"(?i)^rgba?\(\s*(\d{1,3})\s*,\s*(\d{1,3})\s*,\s*(\d{1,3})\s*(?:,\s*([\d.]+)\s*)?\)$",
+    )
+    .ok()?;
+    let caps = re.captures(text)?;
+
+    let component = |i: usize| -> Option<u8> { caps.get(i)?.as_str().parse::<u16>().ok().map(|v| v.min(255) as u8) };
+
+    let r = component(1)?;
+    let g = component(2)?;
+    let b = component(3)?;
+    let a = match caps.get(4) {
+        Some(m) => (m.as_str().parse::<f32>().ok()?.clamp(0.0, 1.0) * 255.0).round() as u8,
+        None => 255,
+    };
+
+    Some([r, g, b, a])
+}
+
 /// Detect URLs in text
 fn detect_urls(text: &str) -> Vec<String> {
     let url_pattern = regex::Regex::new(r"https?://[^\s]+").ok();
@@ -322,15 +969,30 @@ fn detect_urls(text: &str) -> Vec<String> {
 
 // Non-macOS fallback implementations
 #[cfg(not(target_os = "macos"))]
-pub fn read_clipboard_content() -> Option<NativeClipboardItem> {
+pub fn read_clipboard_content(_config: &ClipboardReadConfig) -> Option<NativeClipboardItem> {
     None
 }
 
+#[cfg(not(target_os = "macos"))]
+pub fn read_clipboard_items(_config: &ClipboardReadConfig) -> Vec<NativeClipboardItem> {
+    Vec::new()
+}
+
 #[cfg(not(target_os = "macos"))]
 pub fn get_clipboard_change_count() -> i64 {
     0
 }
 
+#[cfg(not(target_os = "macos"))]
+fn get_frontmost_app_bundle_id() -> Option<String> {
+    None
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn clipboard_write(_item: NativeClipboardWrite) -> i64 {
+    0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -358,4 +1020,117 @@ mod tests {
         let urls = detect_urls(text);
         assert!(urls.is_empty());
     }
+
+    #[test]
+    fn test_parse_css_color_short_hex() {
+        assert_eq!(parse_css_color("#fff"), Some([255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn test_parse_css_color_hex_with_alpha() {
+        assert_eq!(parse_css_color("#112233ff"), Some([0x11, 0x22, 0x33, 0xff]));
+    }
+
+    #[test]
+    fn test_parse_css_color_rgb_function() {
+        assert_eq!(parse_css_color("rgb(10, 20, 30)"), Some([10, 20, 30, 255]));
+    }
+
+    #[test]
+    fn test_parse_css_color_rgba_function() {
+        assert_eq!(
+            parse_css_color("rgba(10, 20, 30, 0.5)"),
+            Some([10, 20, 30, 128])
+        );
+    }
+
+    #[test]
+    fn test_parse_css_color_rejects_plain_text() {
+        assert_eq!(parse_css_color("not a color"), None);
+    }
+
+    /// Build a minimal PNG: 8-byte signature, 8-byte IHDR chunk header, then
+    /// big-endian width/height, matching what [`parse_png_dimensions`] reads.
+    fn build_png(width: u32, height: u32) -> Vec<u8> {
+        let mut data = vec![0u8; 24];
+        data[16..20].copy_from_slice(&width.to_be_bytes());
+        data[20..24].copy_from_slice(&height.to_be_bytes());
+        data
+    }
+
+    /// Build a minimal single-IFD TIFF with `ImageWidth`/`ImageLength` tags
+    /// stored as inline `SHORT` values, in the given byte order.
+    fn build_tiff(little_endian: bool, width: u16, height: u16) -> Vec<u8> {
+        let put_u16 = |buf: &mut Vec<u8>, v: u16| {
+            if little_endian {
+                buf.extend_from_slice(&v.to_le_bytes());
+            } else {
+                buf.extend_from_slice(&v.to_be_bytes());
+            }
+        };
+        let put_u32 = |buf: &mut Vec<u8>, v: u32| {
+            if little_endian {
+                buf.extend_from_slice(&v.to_le_bytes());
+            } else {
+                buf.extend_from_slice(&v.to_be_bytes());
+            }
+        };
+
+        let mut data = Vec::new();
+        data.extend_from_slice(if little_endian { b"II" } else { b"MM" });
+        put_u16(&mut data, 42); // TIFF magic number, unused by our parser
+        put_u32(&mut data, 8); // IFD offset
+        put_u16(&mut data, 2); // entry count
+
+        // ImageWidth (tag 256), type SHORT (3)
+        put_u16(&mut data, 256);
+        put_u16(&mut data, 3);
+        put_u32(&mut data, 1); // count
+        put_u16(&mut data, width);
+        put_u16(&mut data, 0); // pad the 4-byte value slot
+
+        // ImageLength (tag 257), type SHORT (3)
+        put_u16(&mut data, 257);
+        put_u16(&mut data, 3);
+        put_u32(&mut data, 1);
+        put_u16(&mut data, height);
+        put_u16(&mut data, 0);
+
+        data
+    }
+
+    #[test]
+    fn test_parse_png_dimensions() {
+        let data = build_png(640, 480);
+        assert_eq!(parse_png_dimensions(&data), (Some(640), Some(480)));
+    }
+
+    #[test]
+    fn test_parse_png_dimensions_truncated() {
+        let data = build_png(640, 480);
+        assert_eq!(parse_png_dimensions(&data[..20]), (None, None));
+    }
+
+    #[test]
+    fn test_parse_tiff_dimensions_little_endian() {
+        let data = build_tiff(true, 800, 600);
+        assert_eq!(parse_tiff_dimensions(&data), (Some(800), Some(600)));
+    }
+
+    #[test]
+    fn test_parse_tiff_dimensions_big_endian() {
+        let data = build_tiff(false, 1024, 768);
+        assert_eq!(parse_tiff_dimensions(&data), (Some(1024), Some(768)));
+    }
+
+    #[test]
+    fn test_parse_tiff_dimensions_truncated() {
+        let data = build_tiff(true, 800, 600);
+        assert_eq!(parse_tiff_dimensions(&data[..12]), (None, None));
+    }
+
+    #[test]
+    fn test_parse_tiff_dimensions_rejects_bad_byte_order_marker() {
+        assert_eq!(parse_tiff_dimensions(b"XX\x00\x00\x00\x00\x00\x00"), (None, None));
+    }
 }
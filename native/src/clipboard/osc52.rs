@@ -0,0 +1,116 @@
+//! OSC 52 clipboard escape sequences, for headless/SSH/tmux sessions with no
+//! window server to talk to through AppKit.
+
+use napi_derive::napi;
+use std::io::Write;
+
+/// Which pasteboard OSC 52 should target
+#[napi(string_enum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardSelection {
+    Clipboard,
+    Primary,
+}
+
+impl ClipboardSelection {
+    fn code(self) -> char {
+        match self {
+            ClipboardSelection::Clipboard => 'c',
+            ClipboardSelection::Primary => 'p',
+        }
+    }
+}
+
+/// Base64-encode `data` per RFC 4648, with standard `A-Za-z0-9+/` alphabet
+/// and `=` padding. Written by hand so this module has no dependency on the
+/// rest of the crate's clipboard backends.
+fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        let i0 = b0 >> 2;
+        let i1 = ((b0 & 0b0000_0011) << 4) | (b1.unwrap_or(0) >> 4);
+        out.push(ALPHABET[i0 as usize] as char);
+        out.push(ALPHABET[i1 as usize] as char);
+
+        match (b1, b2) {
+            (Some(b1), Some(b2)) => {
+                let i2 = ((b1 & 0b0000_1111) << 2) | (b2 >> 6);
+                let i3 = b2 & 0b0011_1111;
+                out.push(ALPHABET[i2 as usize] as char);
+                out.push(ALPHABET[i3 as usize] as char);
+            }
+            (Some(b1), None) => {
+                let i2 = (b1 & 0b0000_1111) << 2;
+                out.push(ALPHABET[i2 as usize] as char);
+                out.push('=');
+            }
+            (None, _) => {
+                out.push('=');
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+/// Write `text` to the clipboard via an OSC 52 escape sequence, emitted
+/// directly to the controlling tty so it reaches the host terminal even
+/// when the process's own stdout is piped or redirected.
+pub fn write_osc52(text: &str, selection: ClipboardSelection) -> std::io::Result<()> {
+    let encoded = base64_encode(text.as_bytes());
+    let sequence = format!("\x1b]52;{};{}\x07", selection.code(), encoded);
+
+    let mut tty = std::fs::OpenOptions::new().write(true).open("/dev/tty")?;
+    tty.write_all(sequence.as_bytes())
+}
+
+/// True when there's no AppKit window server to write to directly and an
+/// OSC 52-capable terminal is the only viable clipboard path: inside an SSH
+/// session, or on a non-macOS target with a `$TERM` set (tmux, screen, CI).
+pub fn should_use_osc52() -> bool {
+    if std::env::var_os("SSH_TTY").is_some() || std::env::var_os("SSH_CONNECTION").is_some() {
+        return std::env::var_os("TERM").is_some();
+    }
+
+    !cfg!(target_os = "macos") && std::env::var_os("TERM").is_some()
+}
+
+/// Write `text` to the clipboard via OSC 52, returning whether the escape
+/// sequence was written successfully to `/dev/tty`.
+#[napi]
+pub fn clipboard_write_osc52(text: String, selection: Option<ClipboardSelection>) -> bool {
+    write_osc52(&text, selection.unwrap_or(ClipboardSelection::Clipboard)).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode_no_padding() {
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+    }
+
+    #[test]
+    fn test_base64_encode_one_padding_char() {
+        assert_eq!(base64_encode(b"fooba"), "Zm9vYmE=");
+    }
+
+    #[test]
+    fn test_base64_encode_two_padding_chars() {
+        assert_eq!(base64_encode(b"foob"), "Zm9vYg==");
+    }
+
+    #[test]
+    fn test_base64_encode_empty() {
+        assert_eq!(base64_encode(b""), "");
+    }
+}
@@ -2,6 +2,7 @@
 
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 
 /// Content type of a clipboard item
@@ -70,6 +71,46 @@ pub struct NativeClipboardItem {
 
     // Detected content
     pub detected_urls: Option<Vec<String>>,
+    /// Title of a link item, from the `public.url-name` pasteboard type
+    pub url_title: Option<String>,
+
+    /// Raw bytes for pasteboard types requested via
+    /// `ClipboardReadConfig::extra_uti_types` that aren't natively handled
+    /// above, keyed by UTI (e.g. `org.chromium.pepper-custom-data`)
+    pub custom_data: Option<HashMap<String, Vec<u8>>>,
+    /// Whether the pasteboard carries WebKit's smart-paste marker
+    pub smart_paste: Option<bool>,
+
+    /// Normalized RGBA, populated for `ClipboardContentType::Color` items
+    /// regardless of whether the source was a native `NSColor` or CSS text
+    pub color_rgba: Option<[u8; 4]>,
+}
+
+/// Configuration for reading clipboard content
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct ClipboardReadConfig {
+    /// Transcode TIFF image data to PNG before returning it, so consumers
+    /// see a single predictable `image_format`
+    pub normalize_tiff_to_png: Option<bool>,
+    /// Extra pasteboard UTIs to capture verbatim into `custom_data`, for
+    /// application-specific payloads this crate doesn't natively parse
+    pub extra_uti_types: Option<Vec<String>>,
+}
+
+/// Content to write to the system clipboard
+#[napi(object)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NativeClipboardWrite {
+    pub plain_text: Option<String>,
+    pub html_data: Option<String>,
+    pub rtf_data: Option<String>,
+
+    /// Raw image bytes, paired with `image_format` ("png" or "tiff")
+    pub image_data: Option<Vec<u8>>,
+    pub image_format: Option<String>,
+
+    pub file_paths: Option<Vec<String>>,
 }
 
 /// Configuration for the clipboard monitor
@@ -6,13 +6,41 @@ use napi_derive::napi;
 pub mod clipboard;
 
 // Re-export clipboard types for napi
-pub use clipboard::monitor::{get_clipboard_change_count, read_clipboard_content};
+pub use clipboard::monitor::{
+    clipboard_write as clipboard_write_impl, get_clipboard_change_count, read_clipboard_content,
+    read_clipboard_items, start_clipboard_monitor, stop_clipboard_monitor,
+};
+pub use clipboard::osc52::{clipboard_write_osc52, ClipboardSelection};
 pub use clipboard::types::*;
 
 /// Read the current clipboard and return as a native item
 #[napi]
-pub fn clipboard_read() -> Option<NativeClipboardItem> {
-    read_clipboard_content()
+pub fn clipboard_read(config: Option<ClipboardReadConfig>) -> Option<NativeClipboardItem> {
+    read_clipboard_content(&config.unwrap_or_default())
+}
+
+/// Read every item on the pasteboard individually, preserving multi-item
+/// copies (several files, a link plus a separate title item) that
+/// `clipboard_read` would collapse into a single result.
+#[napi]
+pub fn clipboard_read_items(config: Option<ClipboardReadConfig>) -> Vec<NativeClipboardItem> {
+    read_clipboard_items(&config.unwrap_or_default())
+}
+
+/// Write content to the clipboard, returning the resulting change count.
+///
+/// Also emits the plain text as an OSC 52 escape sequence when there's no
+/// window server to write to directly (SSH, tmux, CI), so it still reaches
+/// the host terminal's clipboard.
+#[napi]
+pub fn clipboard_write(item: NativeClipboardWrite) -> i64 {
+    if clipboard::osc52::should_use_osc52() {
+        if let Some(text) = item.plain_text.as_deref() {
+            let _ = clipboard::osc52::write_osc52(text, ClipboardSelection::Clipboard);
+        }
+    }
+
+    clipboard_write_impl(item)
 }
 
 /// Get the current clipboard change count (for polling)